@@ -9,6 +9,8 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 
 use anyhow::Context as _;
 use schemars::gen::SchemaGenerator;
@@ -28,8 +30,19 @@ use crate::fetch_method::ArtifactFormat;
 /// all of the DotSlash files in the repo.
 pub const REQUIRED_HEADER: &str = "#!/usr/bin/env dotslash";
 
+/// The highest `format_version` this build of DotSlash knows how to parse.
+/// Bump this whenever a format change requires new parsing logic, not for
+/// purely additive fields that older builds can safely ignore.
+pub const CURRENT_FORMAT_VERSION: u32 = 1;
+
+fn current_format_version() -> u32 {
+    CURRENT_FORMAT_VERSION
+}
+
 #[derive(Deserialize, Debug, PartialEq, JsonSchema)]
 pub struct ConfigFile {
+    #[serde(default = "current_format_version")]
+    pub format_version: u32,
     pub name: String,
     pub platforms: HashMap<String, ArtifactEntry>,
 }
@@ -42,9 +55,32 @@ pub struct ArtifactEntry<Format = ArtifactFormat> {
     #[serde(default)]
     pub format: Format,
     pub path: ArtifactPath,
-    pub providers: Vec<Value>,
+    pub providers: Vec<Provider>,
     #[serde(default = "readonly_default_as_true", skip_serializing_if = "is_true")]
     pub readonly: bool,
+    /// A detached signature over the artifact's bytes, checked after the
+    /// `digest` check passes and before the downloaded file is made
+    /// executable. `digest` guards against corruption; `signature` guards
+    /// against a provider serving a different-but-validly-hashed artifact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+    /// Additional `(algorithm, digest)` pairs the downloaded bytes must
+    /// also match, alongside the primary `hash`/`digest` pair above. This
+    /// lets operators dual-publish digests (e.g. `sha256` and `blake3`)
+    /// while migrating a fleet of configs to a new hash algorithm, without
+    /// an atomic flag-day rewrite of every config.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub digests: HashMap<HashAlgorithm, Digest>,
+}
+
+impl<Format> ArtifactEntry<Format> {
+    /// All `(algorithm, digest)` pairs this entry specifies: the primary
+    /// `hash`/`digest` pair, plus any extras in `digests`. Verification
+    /// must check that the downloaded bytes match every digest returned
+    /// here.
+    pub fn all_digests(&self) -> impl Iterator<Item = (&HashAlgorithm, &Digest)> {
+        std::iter::once((&self.hash, &self.digest)).chain(self.digests.iter())
+    }
 }
 
 impl JsonSchema for ArtifactEntry {
@@ -61,10 +97,13 @@ impl JsonSchema for ArtifactEntry {
             #[serde(default)]
             pub format: ArtifactFormat,
             pub path: ArtifactPath,
-            #[schemars(with = "Vec<serde_json::Value>")]
-            pub providers: Vec<Value>,
+            pub providers: Vec<Provider>,
             #[serde(default = "readonly_default_as_true", skip_serializing_if = "is_true")]
             pub readonly: bool,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub signature: Option<Signature>,
+            #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+            pub digests: HashMap<HashAlgorithm, Digest>,
         }
         _ArtifactEntry::json_schema(gen)
     }
@@ -73,6 +112,248 @@ impl JsonSchema for ArtifactEntry {
     }
 }
 
+/// A single provider entry in [`ArtifactEntry::providers`], tagged by its
+/// `"type"` field.
+///
+/// Providers are deserialized leniently: an entry whose `"type"` is not one
+/// this build of DotSlash recognizes falls back to [`Provider::Unknown`]
+/// rather than failing the whole config, so older DotSlash binaries keep
+/// working against configs that reference newer provider types.
+#[derive(Debug, PartialEq)]
+pub enum Provider {
+    Http(HttpProvider),
+    S3(S3Provider),
+    /// A provider entry whose `"type"` is not recognized by this build.
+    /// Preserved verbatim so it round-trips through serialization.
+    Unknown(Value),
+}
+
+impl<'de> serde::Deserialize<'de> for Provider {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        #[derive(Deserialize)]
+        struct Tag {
+            #[serde(rename = "type")]
+            ty: String,
+        }
+        let Tag { ty } =
+            Tag::deserialize(&value).map_err(|e| serde::de::Error::custom(e.to_string()))?;
+        match ty.as_str() {
+            "http" => HttpProvider::deserialize(&value)
+                .map(Provider::Http)
+                .map_err(|e| serde::de::Error::custom(e.to_string())),
+            "s3" => S3Provider::deserialize(&value)
+                .map(Provider::S3)
+                .map_err(|e| serde::de::Error::custom(e.to_string())),
+            _ => Ok(Provider::Unknown(value)),
+        }
+    }
+}
+
+impl serde::Serialize for Provider {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        #[serde(tag = "type")]
+        enum Tagged<'a> {
+            #[serde(rename = "http")]
+            Http(&'a HttpProvider),
+            #[serde(rename = "s3")]
+            S3(&'a S3Provider),
+        }
+        match self {
+            Provider::Http(http) => Tagged::Http(http).serialize(serializer),
+            Provider::S3(s3) => Tagged::S3(s3).serialize(serializer),
+            Provider::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl JsonSchema for Provider {
+    fn schema_name() -> String {
+        String::from("Provider")
+    }
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        #[derive(JsonSchema)]
+        #[serde(tag = "type")]
+        #[allow(dead_code)]
+        enum _KnownProvider {
+            #[serde(rename = "http")]
+            Http(HttpProvider),
+            #[serde(rename = "s3")]
+            S3(S3Provider),
+        }
+        let known = gen.subschema_for::<_KnownProvider>();
+        // Besides the provider types this build understands, also accept
+        // any object whose `"type"` is NOT one of the known ones above, so
+        // that configs referencing newer provider types still validate; see
+        // `Provider::Unknown`. Excluding the known types (rather than just
+        // requiring a `"type"` property) keeps this branch from also
+        // matching a malformed entry of a known type that `known` already
+        // rejects, which would otherwise mask the precise error.
+        let not_a_known_type = Schema::Object(schemars::schema::SchemaObject {
+            enum_values: Some(vec!["http".into(), "s3".into()]),
+            ..Default::default()
+        });
+        let mut properties = schemars::Map::new();
+        properties.insert(
+            "type".to_owned(),
+            Schema::Object(schemars::schema::SchemaObject {
+                subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                    not: Some(Box::new(not_a_known_type)),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }),
+        );
+        let unrecognized = Schema::Object(schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                required: ["type".to_owned()].into_iter().collect(),
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+        Schema::Object(schemars::schema::SchemaObject {
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![known, unrecognized]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(std::concat!(std::module_path!(), "::", "Provider"))
+    }
+}
+
+/// An `s3` provider entry, for artifacts hosted in private S3-compatible
+/// buckets that the plain `http` provider can't reach because the request
+/// needs to be signed.
+///
+/// Models the two ways a DotSlash file can express that signing: a
+/// presigned GET URL that's already valid as-is, or a full S3 POST Object
+/// descriptor (the policy document, the bucket/key/region, and the
+/// accompanying signature fields), mirroring what browsers and SDKs send
+/// for direct-to-S3 uploads.
+#[derive(Deserialize, Serialize, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum S3Provider {
+    PresignedGet(S3PresignedGet),
+    PostForm(S3PostForm),
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, JsonSchema)]
+pub struct S3PresignedGet {
+    /// A presigned GET URL. Requires no further signing: the signature is
+    /// already embedded in the URL's query string.
+    pub url: ProviderUrl,
+}
+
+/// Fields accompanying an S3 POST Object request, following the field
+/// layout of S3's POST Object policy document.
+#[derive(Deserialize, Serialize, Debug, PartialEq, JsonSchema)]
+pub struct S3PostForm {
+    pub bucket: String,
+    pub key: String,
+    pub region: String,
+    /// The S3-compatible endpoint to POST to. Defaults to AWS S3 when
+    /// omitted; set this to target other S3-compatible providers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// The base64-encoded policy document the signature was computed over.
+    pub policy: String,
+    #[serde(rename = "x-amz-algorithm")]
+    pub algorithm: String,
+    #[serde(rename = "x-amz-credential")]
+    pub credential: String,
+    #[serde(rename = "x-amz-date")]
+    pub date: String,
+    #[serde(rename = "x-amz-signature")]
+    pub signature: String,
+    /// The `(min, max)` byte range the policy's `content-length-range`
+    /// condition allows, if the policy constrains it.
+    #[serde(
+        default,
+        rename = "content-length-range",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub content_length_range: Option<(u64, u64)>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, JsonSchema)]
+pub struct HttpProvider {
+    pub url: ProviderUrl,
+}
+
+/// A provider URL, canonicalized when parsed out of a DotSlash file: the
+/// host is lowercased and IDNA/punycode-encoded so that two configs that
+/// spell the same URL differently (trailing slash, mixed-case or
+/// internationalized host) compare equal. `url::Url::parse` already does
+/// this canonicalization (and already rejects hosts with control
+/// characters, whitespace, or other characters the WHATWG URL parser
+/// forbids), so there's no additional host validation to do here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderUrl(url::Url);
+
+impl ProviderUrl {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl FromStr for ProviderUrl {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let url = url::Url::parse(s).with_context(|| format!("invalid provider url `{s}`"))?;
+        Ok(ProviderUrl(url))
+    }
+}
+
+impl fmt::Display for ProviderUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ProviderUrl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ProviderUrl::from_str(&s).map_err(|err| serde::de::Error::custom(err.to_string()))
+    }
+}
+
+impl serde::Serialize for ProviderUrl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl JsonSchema for ProviderUrl {
+    fn schema_name() -> String {
+        String::from("ProviderUrl")
+    }
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(std::concat!(std::module_path!(), "::", "ProviderUrl"))
+    }
+}
+
 /// While having a boolean that defaults to `true` is somewhat undesirable,
 /// the alternative would be to name the field "writable", which is too easy
 /// to misspell as "writeable" (which would be ignored), so "readonly" it is.
@@ -85,12 +366,76 @@ fn is_true(b: &bool) -> bool {
     *b
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, JsonSchema)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Hash, JsonSchema)]
 pub enum HashAlgorithm {
     #[serde(rename = "blake3")]
     Blake3,
     #[serde(rename = "sha256")]
     Sha256,
+    #[serde(rename = "sha512")]
+    Sha512,
+}
+
+/// A detached signature asserting that an artifact's bytes were produced by
+/// a trusted publisher, as opposed to merely matching a recorded digest.
+#[derive(Deserialize, Serialize, Debug, PartialEq, JsonSchema)]
+pub struct Signature {
+    pub scheme: SignatureScheme,
+    /// The signer's public key, or a key id understood by `scheme`,
+    /// embedded directly so verification doesn't require a separate
+    /// keyring lookup.
+    pub public_key: String,
+    /// The detached signature over the artifact's raw bytes, encoded the
+    /// way `scheme` natively represents it (e.g. minisign's base64 blob).
+    pub signature: String,
+}
+
+/// The `sshsig` namespace DotSlash signs under for [`SignatureScheme::SshEd25519`]
+/// (see `ssh-keygen -Y sign -n <namespace>`). Pinning this means a signature
+/// produced for some other purpose with the same key can't be replayed here.
+const SSH_SIGNATURE_NAMESPACE: &str = "dotslash-artifact";
+
+impl Signature {
+    /// Verifies this signature over the downloaded artifact `bytes`,
+    /// dispatching on `scheme` for the matching decode/verify routine.
+    /// Fails closed: a malformed `public_key`/`signature` encoding, or a
+    /// signature that doesn't match `bytes`, is an error rather than a
+    /// silent pass. The caller is expected to run this after the `digest`
+    /// check passes and before the downloaded file is made executable.
+    pub fn verify(&self, bytes: &[u8]) -> anyhow::Result<()> {
+        match self.scheme {
+            SignatureScheme::Minisign => {
+                let public_key = minisign_verify::PublicKey::from_base64(&self.public_key)
+                    .context("invalid minisign public key")?;
+                let signature = minisign_verify::Signature::decode(&self.signature)
+                    .context("invalid minisign signature")?;
+                public_key
+                    .verify(bytes, &signature, false)
+                    .context("minisign signature does not match artifact bytes")
+            }
+            SignatureScheme::SshEd25519 => {
+                let public_key: ssh_key::PublicKey = self
+                    .public_key
+                    .parse()
+                    .context("invalid ssh-ed25519 public key")?;
+                let signature: ssh_key::SshSig = self
+                    .signature
+                    .parse()
+                    .context("invalid ssh-ed25519 signature")?;
+                public_key
+                    .verify(SSH_SIGNATURE_NAMESPACE, bytes, &signature)
+                    .context("ssh-ed25519 signature does not match artifact bytes")
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, JsonSchema)]
+pub enum SignatureScheme {
+    #[serde(rename = "minisign")]
+    Minisign,
+    #[serde(rename = "ssh-ed25519")]
+    SshEd25519,
 }
 
 pub fn parse_file(data: &str) -> anyhow::Result<(Value, ConfigFile)> {
@@ -106,10 +451,48 @@ pub fn parse_file(data: &str) -> anyhow::Result<(Value, ConfigFile)> {
         })?;
 
     let value = serde_jsonrc::from_str::<Value>(data)?;
+
+    if let Some(format_version) = value.get("format_version") {
+        let format_version = format_version
+            .as_u64()
+            .with_context(|| "`format_version` must be a non-negative integer")?;
+        if format_version > u64::from(CURRENT_FORMAT_VERSION) {
+            anyhow::bail!(
+                "this DotSlash file requires format_version {format_version}, but this build of \
+                 dotslash only understands up to format_version {CURRENT_FORMAT_VERSION}; \
+                 upgrade dotslash to use it"
+            );
+        }
+    }
+
+    validate_against_schema(&value)?;
+
     let config_file = ConfigFile::deserialize(&value)?;
     Ok((value, config_file))
 }
 
+/// Validates `value` against the JSON Schema generated for [`ConfigFile`],
+/// producing precise, path-annotated errors before we attempt a full serde
+/// deserialize (whose errors are comparatively hard to act on).
+fn validate_against_schema(value: &Value) -> anyhow::Result<()> {
+    let schema = serde_json::to_value(schemars::schema_for!(ConfigFile))
+        .context("failed to serialize the ConfigFile schema")?;
+    let validator = jsonschema::JSONSchema::compile(&schema)
+        .map_err(|e| anyhow::format_err!("failed to compile the ConfigFile schema: {e}"))?;
+    let instance = serde_json::to_value(value)
+        .context("failed to convert the DotSlash file to JSON for schema validation")?;
+    if let Err(errors) = validator.validate(&instance) {
+        let errors: Vec<String> = errors
+            .map(|error| format!("{}: {error}", error.instance_path))
+            .collect();
+        anyhow::bail!(
+            "DotSlash file does not match the expected schema:\n{}",
+            errors.join("\n")
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -155,6 +538,7 @@ mod tests {
         assert_eq!(
             config_file,
             ConfigFile {
+                format_version: CURRENT_FORMAT_VERSION,
                 name: "my_tool".to_owned(),
                 platforms: [(
                     "linux-x86_64".to_owned(),
@@ -168,11 +552,12 @@ mod tests {
                         .unwrap(),
                         format: ArtifactFormat::Tar,
                         path: ArtifactPath::from_str("bindir/my_tool").unwrap(),
-                        providers: vec![serde_jsonrc::json!({
-                            "type": "http",
-                            "url": "https://example.com/my_tool.tar",
+                        providers: vec![Provider::Http(HttpProvider {
+                            url: ProviderUrl::from_str("https://example.com/my_tool.tar").unwrap(),
                         })],
                         readonly: true,
+                        signature: None,
+                        digests: HashMap::new(),
                     }
                 )]
                 .into(),
@@ -205,6 +590,7 @@ mod tests {
         assert_eq!(
             config_file,
             ConfigFile {
+                format_version: CURRENT_FORMAT_VERSION,
                 name: "minesweeper".to_owned(),
                 platforms: [(
                     "linux-x86_64".to_owned(),
@@ -218,11 +604,12 @@ mod tests {
                         .unwrap(),
                         format: ArtifactFormat::Plain,
                         path: ArtifactPath::from_str("minesweeper.exe").unwrap(),
-                        providers: vec![serde_jsonrc::json!({
-                            "type": "http",
-                            "url": "https://foo.com",
+                        providers: vec![Provider::Http(HttpProvider {
+                            url: ProviderUrl::from_str("https://foo.com").unwrap(),
                         })],
                         readonly: true,
+                        signature: None,
+                        digests: HashMap::new(),
                     }
                 )]
                 .into(),
@@ -230,6 +617,329 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unknown_provider_type_is_preserved() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "gcs",
+                            "bucket": "my-bucket"
+                        }
+                    ],
+                },
+            },
+        }
+        "#;
+        let config_file = parse_file_string(dotslash).unwrap();
+        let providers = &config_file.platforms["linux-x86_64"].providers;
+        assert_eq!(
+            providers,
+            &vec![Provider::Unknown(serde_jsonrc::json!({
+                "type": "gcs",
+                "bucket": "my-bucket",
+            }))],
+        );
+    }
+
+    #[test]
+    fn malformed_known_provider_type_is_rejected_by_schema() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "http"
+                        }
+                    ],
+                },
+            },
+        }
+        "#;
+        let err = parse_file_string(dotslash).unwrap_err().to_string();
+        assert!(
+            err.contains("does not match the expected schema"),
+            "unexpected error message: {err}"
+        );
+    }
+
+    #[test]
+    fn malformed_s3_provider_is_rejected_by_schema() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "s3"
+                        }
+                    ],
+                },
+            },
+        }
+        "#;
+        let err = parse_file_string(dotslash).unwrap_err().to_string();
+        assert!(
+            err.contains("does not match the expected schema"),
+            "unexpected error message: {err}"
+        );
+    }
+
+    #[test]
+    fn provider_url_host_is_canonicalized() {
+        let url = ProviderUrl::from_str("https://EXAMPLE.COM/my_tool.tar").unwrap();
+        assert_eq!(url.as_str(), "https://example.com/my_tool.tar");
+
+        let url = ProviderUrl::from_str("https://ドメイン.テスト/my_tool.tar").unwrap();
+        assert_eq!(url.as_str(), "https://xn--eckwd4c7c.xn--zckzah/my_tool.tar");
+    }
+
+    #[test]
+    fn provider_url_rejects_forbidden_host_chars() {
+        assert!(ProviderUrl::from_str("https://exa mple.com/my_tool.tar").is_err());
+    }
+
+    #[test]
+    fn parses_optional_signature() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "http",
+                            "url": "https://example.com/my_tool.tar"
+                        }
+                    ],
+                    "signature": {
+                        "scheme": "minisign",
+                        "public_key": "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3",
+                        "signature": "dW50cnVzdGVkIGNvbW1lbnQ6..."
+                    }
+                },
+            },
+        }
+        "#;
+        let config_file = parse_file_string(dotslash).unwrap();
+        let entry = &config_file.platforms["linux-x86_64"];
+        assert_eq!(
+            entry.signature,
+            Some(Signature {
+                scheme: SignatureScheme::Minisign,
+                public_key: "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3".to_owned(),
+                signature: "dW50cnVzdGVkIGNvbW1lbnQ6...".to_owned(),
+            }),
+        );
+    }
+
+    #[test]
+    fn minisign_signature_verifies_artifact_bytes() {
+        let signature = Signature {
+            scheme: SignatureScheme::Minisign,
+            public_key: "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3".to_owned(),
+            signature: "untrusted comment: signature from minisign secret key\n\
+                RUQf6LRCGA9i559r3g7V1qNyJDApGip8MfqcadIgT9CuhV3EMhHoN1mGTkUidF/\
+                z7SrlQgXdy8ofjb7bNJJylDOocrCo8KLzZwo=\n\
+                trusted comment: timestamp:1556193335\tfile:test\n\
+                y/rUw2y8/hOUYjZU71eHp/Wo1KZ40fGy2VJEDl34XMJM+TX48Ss/17u3IvIfbVR1FkZZSNCisQbuQY+bHwhEBg=="
+                .to_owned(),
+        };
+        signature.verify(b"test").unwrap();
+
+        let err = signature.verify(b"not the signed bytes").unwrap_err();
+        assert!(
+            err.to_string().contains("does not match"),
+            "unexpected error message: {err}"
+        );
+    }
+
+    #[test]
+    fn ssh_ed25519_signature_verifies_artifact_bytes() {
+        let signature = Signature {
+            scheme: SignatureScheme::SshEd25519,
+            public_key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIFIxWnzclIrz58VaQTTpOYjzRaEbE9Gh9ChUBFk2Flyx"
+                .to_owned(),
+            signature: "-----BEGIN SSH SIGNATURE-----\n\
+                U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAgUjFafNyUivPnxVpBNOk5iPNFoR\n\
+                sT0aH0KFQEWTYWXLEAAAARZG90c2xhc2gtYXJ0aWZhY3QAAAAAAAAABnNoYTUxMgAAAFMA\n\
+                AAALc3NoLWVkMjU1MTkAAABAATjdYxMmB5Fq5JwGZeTzcQ+UUHx627oP+DGii6KKBcEQdN\n\
+                SastGDSd7DB7lJVF/8AT51wQt4HXL37wrYhjN2AQ==\n\
+                -----END SSH SIGNATURE-----\n"
+                .to_owned(),
+        };
+        signature.verify(b"hello artifact bytes").unwrap();
+
+        let err = signature.verify(b"not the signed bytes").unwrap_err();
+        assert!(
+            err.to_string().contains("does not match"),
+            "unexpected error message: {err}"
+        );
+    }
+
+    #[test]
+    fn parses_additional_digests() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "http",
+                            "url": "https://example.com/my_tool.tar"
+                        }
+                    ],
+                    "digests": {
+                        "blake3": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069"
+                    }
+                },
+            },
+        }
+        "#;
+        let config_file = parse_file_string(dotslash).unwrap();
+        let entry = &config_file.platforms["linux-x86_64"];
+        assert_eq!(entry.digests.len(), 1);
+        assert_eq!(entry.all_digests().count(), 2);
+    }
+
+    #[test]
+    fn parses_s3_presigned_get_provider() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "s3",
+                            "url": "https://my-bucket.s3.amazonaws.com/my_tool.tar?X-Amz-Signature=abc"
+                        }
+                    ],
+                },
+            },
+        }
+        "#;
+        let config_file = parse_file_string(dotslash).unwrap();
+        let providers = &config_file.platforms["linux-x86_64"].providers;
+        assert_eq!(
+            providers,
+            &vec![Provider::S3(S3Provider::PresignedGet(S3PresignedGet {
+                url: ProviderUrl::from_str(
+                    "https://my-bucket.s3.amazonaws.com/my_tool.tar?X-Amz-Signature=abc"
+                )
+                .unwrap(),
+            }))],
+        );
+    }
+
+    #[test]
+    fn parses_s3_post_form_provider() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "my_tool",
+            "platforms": {
+                "linux-x86_64": {
+                    "size": 123,
+                    "hash": "sha256",
+                    "digest": "7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069",
+                    "path": "bindir/my_tool",
+                    "providers": [
+                        {
+                            "type": "s3",
+                            "bucket": "my-bucket",
+                            "key": "my_tool.tar",
+                            "region": "us-east-1",
+                            "policy": "eyJjb25kaXRpb25zIjogW119",
+                            "x-amz-algorithm": "AWS4-HMAC-SHA256",
+                            "x-amz-credential": "AKIA.../20260726/us-east-1/s3/aws4_request",
+                            "x-amz-date": "20260726T000000Z",
+                            "x-amz-signature": "abc123"
+                        }
+                    ],
+                },
+            },
+        }
+        "#;
+        let config_file = parse_file_string(dotslash).unwrap();
+        let providers = &config_file.platforms["linux-x86_64"].providers;
+        assert_eq!(
+            providers,
+            &vec![Provider::S3(S3Provider::PostForm(S3PostForm {
+                bucket: "my-bucket".to_owned(),
+                key: "my_tool.tar".to_owned(),
+                region: "us-east-1".to_owned(),
+                endpoint: None,
+                policy: "eyJjb25kaXRpb25zIjogW119".to_owned(),
+                algorithm: "AWS4-HMAC-SHA256".to_owned(),
+                credential: "AKIA.../20260726/us-east-1/s3/aws4_request".to_owned(),
+                date: "20260726T000000Z".to_owned(),
+                signature: "abc123".to_owned(),
+                content_length_range: None,
+            }))],
+        );
+    }
+
+    #[test]
+    fn format_version_defaults_when_absent() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "name": "made-up",
+            "platforms": {
+            },
+        }
+        "#;
+        let config_file = parse_file_string(dotslash).unwrap();
+        assert_eq!(config_file.format_version, CURRENT_FORMAT_VERSION);
+    }
+
+    #[test]
+    fn format_version_too_new_is_rejected() {
+        let dotslash = r#"#!/usr/bin/env dotslash
+        {
+            "format_version": 999999,
+            "name": "made-up",
+            "platforms": {
+            },
+        }
+        "#;
+        let err = parse_file_string(dotslash).unwrap_err().to_string();
+        assert!(
+            err.contains("upgrade dotslash"),
+            "unexpected error message: {err}"
+        );
+    }
+
     #[test]
     fn header_must_be_present() {
         let dotslash = r#"